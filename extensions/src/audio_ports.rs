@@ -19,6 +19,13 @@ pub struct AudioPortType<'a>(pub &'a CStr);
 impl AudioPortType<'_> {
     pub const MONO: AudioPortType<'static> = AudioPortType(CLAP_PORT_MONO);
     pub const STEREO: AudioPortType<'static> = AudioPortType(CLAP_PORT_STEREO);
+    /// A surround layout, whose actual speaker assignment is undefined without a channel map.
+    ///
+    /// See the [`surround`](crate::surround) extension.
+    pub const SURROUND: AudioPortType<'static> = AudioPortType(CLAP_PORT_SURROUND);
+    /// An ambisonic layout, whose actual channel ordering and normalization is
+    /// extension-defined.
+    pub const AMBISONIC: AudioPortType<'static> = AudioPortType(CLAP_PORT_AMBISONIC);
 
     #[inline]
     pub const fn from_channel_count(channel_count: u32) -> Option<Self> {
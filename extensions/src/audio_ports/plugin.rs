@@ -4,6 +4,9 @@ use clack_plugin::extensions::prelude::*;
 use clap_sys::ext::audio_ports::{clap_audio_port_info, clap_plugin_audio_ports};
 use std::mem::MaybeUninit;
 
+mod layout;
+pub use layout::*;
+
 pub struct AudioPortInfoWriter<'a> {
     buf: &'a mut MaybeUninit<clap_audio_port_info>,
     is_set: bool,
@@ -0,0 +1,331 @@
+use crate::audio_ports::{AudioPortFlags, AudioPortInfo, AudioPortInfoWriter, AudioPortType};
+use crate::audio_ports::plugin::PluginAudioPortsImpl;
+use clack_common::utils::ClapId;
+
+#[derive(Clone)]
+struct OwnedAudioPortInfo {
+    id: ClapId,
+    name: Vec<u8>,
+    channel_count: u32,
+    flags: AudioPortFlags,
+    port_type: Option<AudioPortType<'static>>,
+    in_place_pair: Option<ClapId>,
+}
+
+impl OwnedAudioPortInfo {
+    fn as_info(&self) -> AudioPortInfo {
+        AudioPortInfo {
+            id: self.id,
+            name: &self.name,
+            channel_count: self.channel_count,
+            flags: self.flags,
+            port_type: self.port_type,
+            in_place_pair: self.in_place_pair,
+        }
+    }
+}
+
+/// A declarative, builder-style description of a plugin's audio port layout.
+///
+/// Writing [`PluginAudioPortsImpl::count`]/[`get`](PluginAudioPortsImpl::get) by hand becomes
+/// error-prone once a plugin has a main bus plus sidechain or auxiliary busses. `AudioPortLayout`
+/// instead collects owned port descriptors per direction, enforces CLAP's invariants (a main
+/// port must be at index 0, there can be at most one main port per direction, and ids must be
+/// unique within a direction) as soon as a port is added, and blanket-implements
+/// [`PluginAudioPortsImpl`] so a plugin only has to store one `AudioPortLayout`.
+///
+/// # Example
+/// ```no_run
+/// use clack_plugin::audio_ports::{AudioPortLayout, AudioPortInfo, AudioPortFlags, AudioPortType};
+/// use clack_plugin::extensions::prelude::ClapId;
+///
+/// let layout = AudioPortLayout::new()
+///     .add_input(AudioPortInfo {
+///         id: ClapId::new(0),
+///         name: b"Stereo In",
+///         channel_count: 2,
+///         flags: AudioPortFlags::IS_MAIN,
+///         port_type: Some(AudioPortType::STEREO),
+///         in_place_pair: None,
+///     })
+///     .add_output(AudioPortInfo {
+///         id: ClapId::new(0),
+///         name: b"Stereo Out",
+///         channel_count: 2,
+///         flags: AudioPortFlags::IS_MAIN,
+///         port_type: Some(AudioPortType::STEREO),
+///         in_place_pair: None,
+///     })
+///     .in_place_pair(0, 0);
+/// ```
+#[derive(Clone)]
+pub struct AudioPortLayout {
+    inputs: Vec<OwnedAudioPortInfo>,
+    outputs: Vec<OwnedAudioPortInfo>,
+}
+
+impl AudioPortLayout {
+    /// Creates a new, empty port layout.
+    pub fn new() -> Self {
+        Self {
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Adds a main input port.
+    ///
+    /// # Panics
+    /// Panics if any input port (main or auxiliary) was already added, as the main input port
+    /// must be at index 0, or if `info` isn't flagged as [`AudioPortFlags::IS_MAIN`].
+    pub fn add_input(mut self, info: AudioPortInfo) -> Self {
+        assert!(
+            info.flags.contains(AudioPortFlags::IS_MAIN),
+            "the main input port must have the AudioPortFlags::IS_MAIN flag set"
+        );
+        assert!(
+            !Self::has_main(&self.inputs),
+            "only one main input port is allowed"
+        );
+        assert!(
+            self.inputs.is_empty(),
+            "the main input port must be added first, before any auxiliary input port"
+        );
+
+        self.push(true, info);
+        self
+    }
+
+    /// Adds a main output port.
+    ///
+    /// # Panics
+    /// Panics if any output port (main or auxiliary) was already added, as the main output port
+    /// must be at index 0, or if `info` isn't flagged as [`AudioPortFlags::IS_MAIN`].
+    pub fn add_output(mut self, info: AudioPortInfo) -> Self {
+        assert!(
+            info.flags.contains(AudioPortFlags::IS_MAIN),
+            "the main output port must have the AudioPortFlags::IS_MAIN flag set"
+        );
+        assert!(
+            !Self::has_main(&self.outputs),
+            "only one main output port is allowed"
+        );
+        assert!(
+            self.outputs.is_empty(),
+            "the main output port must be added first, before any auxiliary output port"
+        );
+
+        self.push(false, info);
+        self
+    }
+
+    /// Adds an auxiliary (e.g. sidechain) input port.
+    ///
+    /// # Panics
+    /// Panics if `info` is flagged as [`AudioPortFlags::IS_MAIN`], or if an input port with the
+    /// same id already exists.
+    pub fn add_aux_input(mut self, info: AudioPortInfo) -> Self {
+        assert!(
+            !info.flags.contains(AudioPortFlags::IS_MAIN),
+            "only the main input port, added with add_input, may have the AudioPortFlags::IS_MAIN flag set"
+        );
+
+        self.push(true, info);
+        self
+    }
+
+    /// Adds an auxiliary (e.g. sidechain or send) output port.
+    ///
+    /// # Panics
+    /// Panics if `info` is flagged as [`AudioPortFlags::IS_MAIN`], or if an output port with the
+    /// same id already exists.
+    pub fn add_aux_output(mut self, info: AudioPortInfo) -> Self {
+        assert!(
+            !info.flags.contains(AudioPortFlags::IS_MAIN),
+            "only the main output port, added with add_output, may have the AudioPortFlags::IS_MAIN flag set"
+        );
+
+        self.push(false, info);
+        self
+    }
+
+    /// Links the input port at `input_index` and the output port at `output_index` as an
+    /// in-place processing pair, setting each port's `in_place_pair` to the other's id.
+    ///
+    /// # Panics
+    /// Panics if either index is out of bounds.
+    pub fn in_place_pair(mut self, input_index: usize, output_index: usize) -> Self {
+        let input_id = self
+            .inputs
+            .get(input_index)
+            .unwrap_or_else(|| panic!("no input port at index {input_index}"))
+            .id;
+        let output_id = self
+            .outputs
+            .get(output_index)
+            .unwrap_or_else(|| panic!("no output port at index {output_index}"))
+            .id;
+
+        self.inputs[input_index].in_place_pair = Some(output_id);
+        self.outputs[output_index].in_place_pair = Some(input_id);
+
+        self
+    }
+
+    fn has_main(ports: &[OwnedAudioPortInfo]) -> bool {
+        ports.iter().any(|p| p.flags.contains(AudioPortFlags::IS_MAIN))
+    }
+
+    fn push(&mut self, is_input: bool, info: AudioPortInfo) {
+        let ports = if is_input {
+            &mut self.inputs
+        } else {
+            &mut self.outputs
+        };
+
+        assert!(
+            ports.iter().all(|p| p.id != info.id),
+            "a port with id {:?} already exists for this direction",
+            info.id
+        );
+
+        ports.push(OwnedAudioPortInfo {
+            id: info.id,
+            name: info.name.to_vec(),
+            channel_count: info.channel_count,
+            flags: info.flags,
+            port_type: info.port_type,
+            in_place_pair: info.in_place_pair,
+        });
+    }
+}
+
+impl Default for AudioPortLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PluginAudioPortsImpl for AudioPortLayout {
+    fn count(&mut self, is_input: bool) -> u32 {
+        let ports = if is_input { &self.inputs } else { &self.outputs };
+        ports.len() as u32
+    }
+
+    fn get(&mut self, index: u32, is_input: bool, writer: &mut AudioPortInfoWriter) {
+        let ports = if is_input { &self.inputs } else { &self.outputs };
+
+        if let Some(port) = ports.get(index as usize) {
+            writer.set(&port.as_info());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap_sys::ext::audio_ports::clap_audio_port_info;
+    use std::mem::MaybeUninit;
+
+    fn get(layout: &mut AudioPortLayout, index: u32, is_input: bool) -> Option<AudioPortInfo<'static>> {
+        let mut buf = MaybeUninit::<clap_audio_port_info>::zeroed();
+        // SAFETY: `buf` is well-aligned and valid for writes.
+        let mut writer = unsafe { AudioPortInfoWriter::from_raw(buf.as_mut_ptr()) };
+        layout.get(index, is_input, &mut writer);
+
+        if !writer.is_set() {
+            return None;
+        }
+
+        // SAFETY: `writer.set` was just called and always fully initializes the buffer. The
+        // returned `AudioPortInfo` borrows `name`, which is leaked for the static lifetime used
+        // by this test-only helper.
+        unsafe { AudioPortInfo::from_raw(Box::leak(Box::new(buf.assume_init()))) }
+    }
+
+    fn main_port(id: u32) -> AudioPortInfo<'static> {
+        AudioPortInfo {
+            id: ClapId::new(id),
+            name: b"Main",
+            channel_count: 2,
+            flags: AudioPortFlags::IS_MAIN,
+            port_type: Some(AudioPortType::STEREO),
+            in_place_pair: None,
+        }
+    }
+
+    fn aux_port(id: u32) -> AudioPortInfo<'static> {
+        AudioPortInfo {
+            id: ClapId::new(id),
+            name: b"Aux",
+            channel_count: 2,
+            flags: AudioPortFlags::empty(),
+            port_type: Some(AudioPortType::STEREO),
+            in_place_pair: None,
+        }
+    }
+
+    #[test]
+    fn counts_and_reports_ports_per_direction() {
+        let mut layout = AudioPortLayout::new()
+            .add_input(main_port(0))
+            .add_output(main_port(0))
+            .add_aux_input(aux_port(1));
+
+        assert_eq!(layout.count(true), 2);
+        assert_eq!(layout.count(false), 1);
+
+        assert_eq!(get(&mut layout, 0, true).unwrap().id, ClapId::new(0));
+        assert_eq!(get(&mut layout, 1, true).unwrap().id, ClapId::new(1));
+        assert_eq!(get(&mut layout, 0, false).unwrap().id, ClapId::new(0));
+        assert!(get(&mut layout, 1, false).is_none());
+    }
+
+    #[test]
+    fn in_place_pair_links_ports_both_ways() {
+        let mut layout = AudioPortLayout::new()
+            .add_input(main_port(0))
+            .add_output(main_port(0))
+            .in_place_pair(0, 0);
+
+        assert_eq!(get(&mut layout, 0, true).unwrap().in_place_pair, Some(ClapId::new(0)));
+        assert_eq!(get(&mut layout, 0, false).unwrap().in_place_pair, Some(ClapId::new(0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "no input port at index")]
+    fn in_place_pair_panics_on_out_of_bounds_input() {
+        AudioPortLayout::new()
+            .add_output(main_port(0))
+            .in_place_pair(0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "IS_MAIN")]
+    fn add_input_panics_without_is_main_flag() {
+        AudioPortLayout::new().add_input(aux_port(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "only one main input port")]
+    fn add_input_panics_on_second_main_port() {
+        AudioPortLayout::new()
+            .add_input(main_port(0))
+            .add_input(main_port(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "IS_MAIN")]
+    fn add_aux_input_panics_with_is_main_flag() {
+        AudioPortLayout::new().add_aux_input(main_port(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "already exists")]
+    fn add_aux_input_panics_on_duplicate_id() {
+        AudioPortLayout::new()
+            .add_input(main_port(0))
+            .add_aux_input(aux_port(1))
+            .add_aux_input(aux_port(1));
+    }
+}
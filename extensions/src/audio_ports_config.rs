@@ -0,0 +1,131 @@
+use crate::audio_ports::AudioPortType;
+use clack_common::extensions::{Extension, HostExtensionSide, PluginExtensionSide, RawExtension};
+use clack_common::utils::ClapId;
+use clap_sys::ext::audio_ports_config::*;
+use std::ffi::CStr;
+use std::fmt::{Debug, Formatter};
+
+#[derive(Copy, Clone)]
+#[allow(dead_code)]
+pub struct PluginAudioPortsConfig(
+    RawExtension<PluginExtensionSide, clap_plugin_audio_ports_config>,
+);
+
+#[derive(Copy, Clone)]
+#[allow(dead_code)]
+pub struct HostAudioPortsConfig(RawExtension<HostExtensionSide, clap_host_audio_ports_config>);
+
+// SAFETY: This type is repr(C) and ABI-compatible with the matching extension type.
+unsafe impl Extension for PluginAudioPortsConfig {
+    const IDENTIFIERS: &[&CStr] = &[CLAP_EXT_AUDIO_PORTS_CONFIG];
+    type ExtensionSide = PluginExtensionSide;
+
+    #[inline]
+    unsafe fn from_raw(raw: RawExtension<Self::ExtensionSide>) -> Self {
+        // SAFETY: the guarantee that this pointer is of the correct type is upheld by the caller.
+        Self(unsafe { raw.cast() })
+    }
+}
+
+// SAFETY: This type is repr(C) and ABI-compatible with the matching extension type.
+unsafe impl Extension for HostAudioPortsConfig {
+    const IDENTIFIERS: &[&CStr] = &[CLAP_EXT_AUDIO_PORTS_CONFIG];
+    type ExtensionSide = HostExtensionSide;
+
+    #[inline]
+    unsafe fn from_raw(raw: RawExtension<Self::ExtensionSide>) -> Self {
+        // SAFETY: the guarantee that this pointer is of the correct type is upheld by the caller.
+        Self(unsafe { raw.cast() })
+    }
+}
+
+/// Describes a single whole-plugin audio ports configuration preset.
+///
+/// This is the Rust equivalent of [`clap_audio_ports_config`](https://github.com/free-audio/clap/blob/29ffcc273b/include/clap/ext/audio-ports-config.h),
+/// one of which a host can select to switch the plugin between e.g. "Mono→Mono" and
+/// "Stereo→Stereo" while it is deactivated.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AudioPortsConfig<'a> {
+    /// Stable identifier for this configuration.
+    pub id: ClapId,
+
+    /// Display name for this configuration. Stored as a UTF-8 byte slice.
+    ///
+    /// > **tip**: use `b""` syntax to set this easily
+    /// > ```rust
+    /// > name = b"Stereo In Place",
+    /// > ```
+    pub name: &'a [u8],
+
+    /// Number of input ports this configuration provides.
+    pub input_port_count: u32,
+    /// Number of output ports this configuration provides.
+    pub output_port_count: u32,
+
+    /// Whether this configuration has a main input port.
+    pub has_main_input: bool,
+    /// Channel count of the main input port, if any.
+    pub main_input_channel_count: u32,
+    /// Type of the main input port, if any.
+    pub main_input_port_type: Option<AudioPortType<'a>>,
+
+    /// Whether this configuration has a main output port.
+    pub has_main_output: bool,
+    /// Channel count of the main output port, if any.
+    pub main_output_channel_count: u32,
+    /// Type of the main output port, if any.
+    pub main_output_port_type: Option<AudioPortType<'a>>,
+}
+
+impl<'a> AudioPortsConfig<'a> {
+    /// # Safety
+    /// The raw main_input_port_type/main_output_port_type pointers must be valid C strings for
+    /// the 'a lifetime.
+    pub unsafe fn from_raw(raw: &'a clap_audio_ports_config) -> Option<Self> {
+        use crate::utils::*;
+
+        Some(Self {
+            id: ClapId::from_raw(raw.id)?,
+            name: data_from_array_buf(&raw.name),
+            input_port_count: raw.input_port_count,
+            output_port_count: raw.output_port_count,
+
+            has_main_input: raw.has_main_input,
+            main_input_channel_count: raw.main_input_channel_count,
+            // SAFETY: validity of the pointer is upheld by the caller
+            main_input_port_type: unsafe { AudioPortType::from_raw(raw.main_input_port_type) },
+
+            has_main_output: raw.has_main_output,
+            main_output_channel_count: raw.main_output_channel_count,
+            // SAFETY: validity of the pointer is upheld by the caller
+            main_output_port_type: unsafe { AudioPortType::from_raw(raw.main_output_port_type) },
+        })
+    }
+}
+
+impl Debug for AudioPortsConfig<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AudioPortsConfig")
+            .field("id", &self.id)
+            .field("name", &String::from_utf8_lossy(self.name))
+            .field("input_port_count", &self.input_port_count)
+            .field("output_port_count", &self.output_port_count)
+            .field("has_main_input", &self.has_main_input)
+            .field("main_input_channel_count", &self.main_input_channel_count)
+            .field("main_input_port_type", &self.main_input_port_type)
+            .field("has_main_output", &self.has_main_output)
+            .field("main_output_channel_count", &self.main_output_channel_count)
+            .field("main_output_port_type", &self.main_output_port_type)
+            .finish()
+    }
+}
+
+#[cfg(feature = "clack-host")]
+mod host;
+#[cfg(feature = "clack-host")]
+pub use host::*;
+
+#[cfg(feature = "clack-plugin")]
+mod plugin;
+#[cfg(feature = "clack-plugin")]
+pub use plugin::*;
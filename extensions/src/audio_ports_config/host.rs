@@ -0,0 +1,90 @@
+use crate::audio_ports_config::{AudioPortsConfig, PluginAudioPortsConfig};
+use clack_common::utils::ClapId;
+use clack_host::extensions::prelude::*;
+use clap_sys::ext::audio_ports_config::clap_audio_ports_config;
+use std::mem::MaybeUninit;
+
+mod matching;
+pub use matching::*;
+
+/// A buffer to store a single [`AudioPortsConfig`] retrieved from a plugin through
+/// [`PluginAudioPortsConfig::get`].
+pub struct AudioPortsConfigBuffer {
+    inner: MaybeUninit<clap_audio_ports_config>,
+    populated: bool,
+}
+
+impl AudioPortsConfigBuffer {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: MaybeUninit::zeroed(),
+            populated: false,
+        }
+    }
+
+    /// Reads the config that was last written into this buffer by [`PluginAudioPortsConfig::get`].
+    ///
+    /// Returns `None` if the buffer hasn't been successfully populated yet, or if the last call
+    /// to `get` failed.
+    #[inline]
+    pub fn as_config(&self) -> Option<AudioPortsConfig> {
+        if !self.populated {
+            return None;
+        }
+
+        // SAFETY: `populated` is only set to true after a successful call to `get`, which always
+        // writes every field.
+        unsafe { AudioPortsConfig::from_raw(self.inner.assume_init_ref()) }
+    }
+}
+
+impl Default for AudioPortsConfigBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PluginAudioPortsConfig {
+    /// Gets the number of configurations the plugin exposes.
+    #[inline]
+    pub fn count(&self, plugin: &mut PluginMainThreadHandle) -> u32 {
+        match plugin.use_extension(&self.0).count {
+            None => 0,
+            // SAFETY: This type ensures the function pointer is valid.
+            Some(count) => unsafe { count(plugin.as_raw()) },
+        }
+    }
+
+    /// Gets the configuration at `index`, writing it into `buffer`.
+    ///
+    /// Returns `true` if the config was written successfully.
+    #[inline]
+    pub fn get(
+        &self,
+        plugin: &mut PluginMainThreadHandle,
+        index: u32,
+        buffer: &mut AudioPortsConfigBuffer,
+    ) -> bool {
+        buffer.populated = match plugin.use_extension(&self.0).get {
+            None => false,
+            // SAFETY: This type ensures the function pointer is valid, and the buffer is valid
+            // for writes.
+            Some(get) => unsafe { get(plugin.as_raw(), index, buffer.inner.as_mut_ptr()) },
+        };
+
+        buffer.populated
+    }
+
+    /// Requests the plugin to switch to the configuration identified by `config_id`.
+    ///
+    /// This can only be called while the plugin is deactivated.
+    #[inline]
+    pub fn select(&self, plugin: &mut PluginMainThreadHandle, config_id: ClapId) -> bool {
+        match plugin.use_extension(&self.0).select {
+            None => false,
+            // SAFETY: This type ensures the function pointer is valid.
+            Some(select) => unsafe { select(plugin.as_raw(), config_id.get()) },
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use crate::audio_ports::AudioPortType;
+use crate::audio_ports_config::{AudioPortsConfig, AudioPortsConfigBuffer, PluginAudioPortsConfig};
+use clack_common::utils::ClapId;
+use clack_host::extensions::prelude::*;
+
+/// The channel layout a host wants to instantiate a plugin with, e.g. the channel counts of the
+/// track the plugin is being dropped onto.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct PortConfigurationTarget {
+    /// Desired number of channels on the plugin's main input.
+    pub input_channels: u32,
+    /// Desired number of channels on the plugin's main output.
+    pub output_channels: u32,
+}
+
+/// Enumerates the plugin's advertised [`AudioPortsConfig`]s through [`PluginAudioPortsConfig`],
+/// and returns the id of the one whose main ports best match `target`, or `None` if the plugin
+/// exposes no configs at all.
+///
+/// Candidates are scored by preferring, in order: an exact channel count match on both main
+/// ports, a config that can up/downmix close to the target channel counts, and finally falling
+/// back to the plugin's default stereo configuration. This lets a host instantiate a plugin on
+/// an arbitrary track without requiring the user to hand-pick a config.
+pub fn find_best_port_configuration(
+    ext: &PluginAudioPortsConfig,
+    plugin: &mut PluginMainThreadHandle,
+    target: PortConfigurationTarget,
+) -> Option<ClapId> {
+    let count = ext.count(plugin);
+    let mut buffer = AudioPortsConfigBuffer::new();
+
+    let mut best: Option<(ClapId, i32)> = None;
+
+    for index in 0..count {
+        if !ext.get(plugin, index, &mut buffer) {
+            continue;
+        }
+
+        let Some(config) = buffer.as_config() else {
+            continue;
+        };
+
+        let score = score_config(&config, target);
+        let is_better = match best {
+            None => true,
+            Some((_, best_score)) => score > best_score,
+        };
+
+        if is_better {
+            best = Some((config.id, score));
+        }
+    }
+
+    best.map(|(id, _)| id)
+}
+
+/// Selects the best matching config among the plugin's advertised configs, and requests the
+/// plugin to switch to it.
+///
+/// Returns `false` if the plugin exposes no configs, or if it rejected the selection.
+pub fn apply_best_port_configuration(
+    ext: &PluginAudioPortsConfig,
+    plugin: &mut PluginMainThreadHandle,
+    target: PortConfigurationTarget,
+) -> bool {
+    match find_best_port_configuration(ext, plugin, target) {
+        None => false,
+        Some(id) => ext.select(plugin, id),
+    }
+}
+
+/// Scores how well a single [`AudioPortsConfig`] matches `target`. Higher is better; `0` means
+/// that in both directions the config either has no main port, or has a non-stereo main port
+/// 10 or more channels away from the target.
+fn score_config(config: &AudioPortsConfig, target: PortConfigurationTarget) -> i32 {
+    score_main_port(
+        config.has_main_input,
+        config.main_input_channel_count,
+        config.main_input_port_type,
+        target.input_channels,
+    ) + score_main_port(
+        config.has_main_output,
+        config.main_output_channel_count,
+        config.main_output_port_type,
+        target.output_channels,
+    )
+}
+
+fn score_main_port(
+    has_main: bool,
+    channel_count: u32,
+    port_type: Option<AudioPortType>,
+    target_channels: u32,
+) -> i32 {
+    if !has_main {
+        return 0;
+    }
+
+    // An exact channel count match always wins.
+    if channel_count == target_channels {
+        return 100;
+    }
+
+    // Otherwise, prefer configs whose main port can up/downmix close to the target, and fall
+    // back to the default stereo pair if nothing else is closer.
+    let distance = (channel_count as i32 - target_channels as i32).abs();
+    let upmix_downmix_score = 10 - distance;
+
+    if port_type == Some(AudioPortType::STEREO) {
+        upmix_downmix_score.max(1)
+    } else {
+        upmix_downmix_score.max(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(
+        has_main_input: bool,
+        main_input_channel_count: u32,
+        main_input_port_type: Option<AudioPortType<'static>>,
+        has_main_output: bool,
+        main_output_channel_count: u32,
+        main_output_port_type: Option<AudioPortType<'static>>,
+    ) -> AudioPortsConfig<'static> {
+        AudioPortsConfig {
+            id: ClapId::new(0),
+            name: b"",
+            input_port_count: 1,
+            output_port_count: 1,
+            has_main_input,
+            main_input_channel_count,
+            main_input_port_type,
+            has_main_output,
+            main_output_channel_count,
+            main_output_port_type,
+        }
+    }
+
+    fn stereo_to_stereo() -> AudioPortsConfig<'static> {
+        config(
+            true,
+            2,
+            Some(AudioPortType::STEREO),
+            true,
+            2,
+            Some(AudioPortType::STEREO),
+        )
+    }
+
+    fn mono_to_mono() -> AudioPortsConfig<'static> {
+        config(
+            true,
+            1,
+            Some(AudioPortType::MONO),
+            true,
+            1,
+            Some(AudioPortType::MONO),
+        )
+    }
+
+    #[test]
+    fn exact_match_beats_everything() {
+        let target = PortConfigurationTarget {
+            input_channels: 2,
+            output_channels: 2,
+        };
+
+        assert!(score_config(&stereo_to_stereo(), target) > score_config(&mono_to_mono(), target));
+    }
+
+    #[test]
+    fn closer_upmix_downmix_candidate_wins() {
+        // Neither config is an exact match for a 7-channel input, but the 6-channel one is
+        // closer than the 10-channel one.
+        let target = PortConfigurationTarget {
+            input_channels: 7,
+            output_channels: 2,
+        };
+
+        let surround_6 = config(
+            true,
+            6,
+            Some(AudioPortType::SURROUND),
+            true,
+            2,
+            Some(AudioPortType::STEREO),
+        );
+        let surround_10 = config(
+            true,
+            10,
+            Some(AudioPortType::SURROUND),
+            true,
+            2,
+            Some(AudioPortType::STEREO),
+        );
+
+        assert!(score_config(&surround_6, target) > score_config(&surround_10, target));
+    }
+
+    #[test]
+    fn stereo_fallback_beats_equally_distant_non_stereo_mismatch() {
+        // Neither config is remotely close to a 64-channel target, and both have the same
+        // channel count (so the same up/downmix distance) - only the stereo type should break
+        // the tie, as the last-resort fallback.
+        let target = PortConfigurationTarget {
+            input_channels: 64,
+            output_channels: 64,
+        };
+
+        let far_stereo = stereo_to_stereo();
+        let far_untyped = config(true, 2, None, true, 2, None);
+
+        assert!(score_config(&far_stereo, target) > score_config(&far_untyped, target));
+    }
+
+    #[test]
+    fn missing_main_port_contributes_no_score() {
+        let target = PortConfigurationTarget {
+            input_channels: 2,
+            output_channels: 2,
+        };
+
+        let output_only = config(false, 0, None, true, 2, Some(AudioPortType::STEREO));
+
+        // Only the main output half of the score should be counted.
+        assert_eq!(
+            score_config(&output_only, target),
+            score_main_port(true, 2, Some(AudioPortType::STEREO), 2)
+        );
+    }
+}
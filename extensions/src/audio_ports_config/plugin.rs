@@ -0,0 +1,163 @@
+use crate::audio_ports_config::{AudioPortsConfig, HostAudioPortsConfig, PluginAudioPortsConfig};
+use crate::utils::write_to_array_buf;
+use clack_plugin::extensions::prelude::*;
+use clap_sys::ext::audio_ports_config::{clap_audio_ports_config, clap_plugin_audio_ports_config};
+use std::mem::MaybeUninit;
+
+pub struct AudioPortsConfigWriter<'a> {
+    buf: &'a mut MaybeUninit<clap_audio_ports_config>,
+    is_set: bool,
+}
+
+impl AudioPortsConfigWriter<'_> {
+    /// # Safety
+    ///
+    /// The user must ensure the provided pointer is aligned and points to a valid allocation.
+    /// However, it doesn't have to be initialized.
+    #[inline]
+    pub(crate) unsafe fn from_raw(raw: *mut clap_audio_ports_config) -> Self {
+        Self {
+            buf: &mut *raw.cast(),
+            is_set: false,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_set(&self) -> bool {
+        self.is_set
+    }
+
+    #[inline]
+    pub fn set(&mut self, data: &AudioPortsConfig) {
+        use core::ptr::write;
+
+        let buf = self.buf.as_mut_ptr();
+
+        // SAFETY: all pointers come from `buf`, which is valid for writes and well-aligned
+        unsafe {
+            write(&raw mut (*buf).id, data.id.get());
+            write_to_array_buf(&raw mut (*buf).name, data.name);
+
+            write(&raw mut (*buf).input_port_count, data.input_port_count);
+            write(&raw mut (*buf).output_port_count, data.output_port_count);
+
+            write(&raw mut (*buf).has_main_input, data.has_main_input);
+            write(
+                &raw mut (*buf).main_input_channel_count,
+                data.main_input_channel_count,
+            );
+            write(
+                &raw mut (*buf).main_input_port_type,
+                data.main_input_port_type
+                    .map(|s| s.as_raw())
+                    .unwrap_or(core::ptr::null()),
+            );
+
+            write(&raw mut (*buf).has_main_output, data.has_main_output);
+            write(
+                &raw mut (*buf).main_output_channel_count,
+                data.main_output_channel_count,
+            );
+            write(
+                &raw mut (*buf).main_output_port_type,
+                data.main_output_port_type
+                    .map(|s| s.as_raw())
+                    .unwrap_or(core::ptr::null()),
+            );
+        }
+
+        self.is_set = true;
+    }
+}
+
+/// Describes the plugin's fixed set of whole-plugin audio port configurations.
+///
+/// A host can use this to present the user with a small set of presets (e.g. "Mono→Mono",
+/// "Stereo→Stereo", "5.1→Stereo") and switch between them via [`select`](PluginAudioPortsConfigImpl::select)
+/// while the plugin is deactivated, instead of negotiating each port individually through
+/// [`PluginAudioPortsImpl`](crate::audio_ports::PluginAudioPortsImpl).
+pub trait PluginAudioPortsConfigImpl {
+    /// Returns the number of available configurations.
+    fn count(&mut self) -> u32;
+
+    /// Populates metadata about the configuration at `index`, using the provided
+    /// [`AudioPortsConfigWriter`].
+    fn get(&mut self, index: u32, writer: &mut AudioPortsConfigWriter);
+
+    /// Requests the plugin to switch to the configuration identified by `config_id`.
+    ///
+    /// This is only ever called while the plugin is deactivated. Returns `true` if the
+    /// configuration was applied successfully.
+    fn select(&mut self, config_id: ClapId) -> bool;
+}
+
+// SAFETY: The given struct is the CLAP extension struct for the matching side of this extension.
+unsafe impl<P> ExtensionImplementation<P> for PluginAudioPortsConfig
+where
+    for<'a> P: Plugin<MainThread<'a>: PluginAudioPortsConfigImpl>,
+{
+    const IMPLEMENTATION: RawExtensionImplementation =
+        RawExtensionImplementation::new(&clap_plugin_audio_ports_config {
+            count: Some(count::<P>),
+            get: Some(get::<P>),
+            select: Some(select::<P>),
+        });
+}
+
+#[allow(clippy::missing_safety_doc)]
+unsafe extern "C" fn count<P>(plugin: *const clap_plugin) -> u32
+where
+    for<'a> P: Plugin<MainThread<'a>: PluginAudioPortsConfigImpl>,
+{
+    PluginWrapper::<P>::handle(plugin, |p| Ok(p.main_thread().as_mut().count())).unwrap_or(0)
+}
+
+#[allow(clippy::missing_safety_doc)]
+unsafe extern "C" fn get<P>(
+    plugin: *const clap_plugin,
+    index: u32,
+    config: *mut clap_audio_ports_config,
+) -> bool
+where
+    for<'a> P: Plugin<MainThread<'a>: PluginAudioPortsConfigImpl>,
+{
+    PluginWrapper::<P>::handle(plugin, |p| {
+        if config.is_null() {
+            return Err(PluginWrapperError::NulPtr("clap_audio_ports_config"));
+        };
+
+        let mut writer = AudioPortsConfigWriter::from_raw(config);
+        p.main_thread().as_mut().get(index, &mut writer);
+        Ok(writer.is_set())
+    })
+    .unwrap_or(false)
+}
+
+#[allow(clippy::missing_safety_doc)]
+unsafe extern "C" fn select<P>(plugin: *const clap_plugin, config_id: clap_id) -> bool
+where
+    for<'a> P: Plugin<MainThread<'a>: PluginAudioPortsConfigImpl>,
+{
+    PluginWrapper::<P>::handle(plugin, |p| {
+        let Some(config_id) = ClapId::from_raw(config_id) else {
+            return Ok(false);
+        };
+
+        Ok(p.main_thread().as_mut().select(config_id))
+    })
+    .unwrap_or(false)
+}
+
+impl HostAudioPortsConfig {
+    /// Tells the host that the available configurations have changed, and that it should
+    /// rescan them.
+    ///
+    /// This can only be called while the plugin is deactivated.
+    #[inline]
+    pub fn rescan(&self, host: &mut HostMainThreadHandle) {
+        if let Some(rescan) = host.use_extension(&self.0).rescan {
+            // SAFETY: This type ensures the function pointer is valid.
+            unsafe { rescan(host.as_raw()) }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::audio_ports::AudioPortType;
+use clack_common::extensions::{Extension, PluginExtensionSide, RawExtension};
+use clap_sys::ext::draft::configurable_audio_ports::*;
+use std::ffi::CStr;
+
+#[derive(Copy, Clone)]
+#[allow(dead_code)]
+pub struct PluginConfigurableAudioPorts(
+    RawExtension<PluginExtensionSide, clap_plugin_configurable_audio_ports>,
+);
+
+// SAFETY: This type is repr(C) and ABI-compatible with the matching extension type.
+unsafe impl Extension for PluginConfigurableAudioPorts {
+    const IDENTIFIERS: &[&CStr] = &[CLAP_EXT_CONFIGURABLE_AUDIO_PORTS];
+    type ExtensionSide = PluginExtensionSide;
+
+    #[inline]
+    unsafe fn from_raw(raw: RawExtension<Self::ExtensionSide>) -> Self {
+        // SAFETY: the guarantee that this pointer is of the correct type is upheld by the caller.
+        Self(unsafe { raw.cast() })
+    }
+}
+
+/// A request to reconfigure a single audio port's channel count and type.
+///
+/// This is the Rust equivalent of [`clap_audio_port_configuration_request`](https://github.com/free-audio/clap/blob/29ffcc273b/include/clap/ext/draft/configurable-audio-ports.h),
+/// used to ask the plugin to apply arbitrary per-port channel counts and types all at once,
+/// instead of picking from the fixed list exposed by
+/// [`PluginAudioPortsConfigImpl`](crate::audio_ports_config::PluginAudioPortsConfigImpl).
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct AudioPortConfigurationRequest<'a> {
+    /// Whether the port being reconfigured is an input port.
+    pub is_input: bool,
+    /// Index of the port being reconfigured.
+    pub port_index: u32,
+    /// Requested channel count for the port.
+    pub channel_count: u32,
+    /// Requested type for the port, if any.
+    pub port_type: Option<AudioPortType<'a>>,
+    /// Opaque, extension-defined details for the requested port.
+    pub port_details: Option<&'a [u8]>,
+}
+
+impl<'a> AudioPortConfigurationRequest<'a> {
+    /// # Safety
+    /// The raw port_type and port_details pointers must be valid for the 'a lifetime.
+    pub unsafe fn from_raw(raw: &'a clap_audio_port_configuration_request) -> Self {
+        Self {
+            is_input: raw.is_input,
+            port_index: raw.port_index,
+            channel_count: raw.channel_count,
+            // SAFETY: validity of the pointer is upheld by the caller
+            port_type: unsafe { AudioPortType::from_raw(raw.port_type) },
+            // SAFETY: validity of the pointer and size is upheld by the caller
+            port_details: unsafe { port_details_from_raw(raw.port_details, raw.port_details_size) },
+        }
+    }
+}
+
+impl AudioPortConfigurationRequest<'_> {
+    /// Builds the raw, C-FFI compatible request matching this one.
+    ///
+    /// The returned value borrows from `self`, and is only valid as long as `self` is.
+    pub(crate) fn to_raw(&self) -> clap_audio_port_configuration_request {
+        clap_audio_port_configuration_request {
+            is_input: self.is_input,
+            port_index: self.port_index,
+            channel_count: self.channel_count,
+            port_type: self
+                .port_type
+                .map(|s| s.as_raw())
+                .unwrap_or(core::ptr::null()),
+            port_details: self
+                .port_details
+                .map(|d| d.as_ptr().cast())
+                .unwrap_or(core::ptr::null()),
+            port_details_size: self.port_details.map(|d| d.len() as u32).unwrap_or(0),
+        }
+    }
+}
+
+unsafe fn port_details_from_raw<'a>(
+    port_details: *const core::ffi::c_void,
+    port_details_size: u32,
+) -> Option<&'a [u8]> {
+    if port_details.is_null() {
+        return None;
+    }
+
+    // SAFETY: the caller guarantees the pointer and size describe a valid allocation
+    Some(unsafe {
+        core::slice::from_raw_parts(port_details.cast(), port_details_size as usize)
+    })
+}
+
+#[cfg(feature = "clack-host")]
+mod host;
+#[cfg(feature = "clack-host")]
+pub use host::*;
+
+#[cfg(feature = "clack-plugin")]
+mod plugin;
+#[cfg(feature = "clack-plugin")]
+pub use plugin::*;
@@ -0,0 +1,41 @@
+use crate::configurable_audio_ports::{AudioPortConfigurationRequest, PluginConfigurableAudioPorts};
+use clack_host::extensions::prelude::*;
+
+impl PluginConfigurableAudioPorts {
+    /// Asks the plugin whether it could apply all of the given configuration requests at once,
+    /// without actually applying them.
+    #[inline]
+    pub fn can_apply_configuration(
+        &self,
+        plugin: &mut PluginMainThreadHandle,
+        requests: &[AudioPortConfigurationRequest],
+    ) -> bool {
+        let raw: Vec<_> = requests.iter().map(|r| r.to_raw()).collect();
+
+        match plugin.use_extension(&self.0).can_apply_configuration {
+            None => false,
+            // SAFETY: This type ensures the function pointer is valid, and `raw` is valid for
+            // `raw.len()` reads for the duration of this call.
+            Some(can_apply) => unsafe { can_apply(plugin.as_raw(), raw.as_ptr(), raw.len() as u32) },
+        }
+    }
+
+    /// Requests the plugin to apply all of the given configuration requests at once.
+    ///
+    /// This can only be called while the plugin is deactivated.
+    #[inline]
+    pub fn apply_configuration(
+        &self,
+        plugin: &mut PluginMainThreadHandle,
+        requests: &[AudioPortConfigurationRequest],
+    ) -> bool {
+        let raw: Vec<_> = requests.iter().map(|r| r.to_raw()).collect();
+
+        match plugin.use_extension(&self.0).apply_configuration {
+            None => false,
+            // SAFETY: This type ensures the function pointer is valid, and `raw` is valid for
+            // `raw.len()` reads for the duration of this call.
+            Some(apply) => unsafe { apply(plugin.as_raw(), raw.as_ptr(), raw.len() as u32) },
+        }
+    }
+}
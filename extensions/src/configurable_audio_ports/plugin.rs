@@ -0,0 +1,91 @@
+use crate::configurable_audio_ports::{AudioPortConfigurationRequest, PluginConfigurableAudioPorts};
+use clack_plugin::extensions::prelude::*;
+use clap_sys::ext::draft::configurable_audio_ports::{
+    clap_audio_port_configuration_request, clap_plugin_configurable_audio_ports,
+};
+
+/// Lets a host request arbitrary per-port channel counts and types all at once, rather than
+/// picking from the plugin's fixed list of [`AudioPortsConfig`](crate::audio_ports_config::AudioPortsConfig)s.
+///
+/// This is used by hosts that need to force a specific render path (e.g. an ARA CLAP loader
+/// forcing a mono render path) on plugins that cannot express it as a static config.
+pub trait PluginConfigurableAudioPortsImpl {
+    /// Returns whether the plugin could apply all of the given configuration requests at once,
+    /// without actually applying them.
+    fn can_apply_configuration(&mut self, requests: &[AudioPortConfigurationRequest]) -> bool;
+
+    /// Requests the plugin to apply all of the given configuration requests at once.
+    ///
+    /// This is only ever called while the plugin is deactivated. Returns `true` if all
+    /// requests were applied successfully.
+    fn apply_configuration(&mut self, requests: &[AudioPortConfigurationRequest]) -> bool;
+}
+
+// SAFETY: The given struct is the CLAP extension struct for the matching side of this extension.
+unsafe impl<P> ExtensionImplementation<P> for PluginConfigurableAudioPorts
+where
+    for<'a> P: Plugin<MainThread<'a>: PluginConfigurableAudioPortsImpl>,
+{
+    const IMPLEMENTATION: RawExtensionImplementation =
+        RawExtensionImplementation::new(&clap_plugin_configurable_audio_ports {
+            can_apply_configuration: Some(can_apply_configuration::<P>),
+            apply_configuration: Some(apply_configuration::<P>),
+        });
+}
+
+#[allow(clippy::missing_safety_doc)]
+unsafe extern "C" fn can_apply_configuration<P>(
+    plugin: *const clap_plugin,
+    requests: *const clap_audio_port_configuration_request,
+    request_count: u32,
+) -> bool
+where
+    for<'a> P: Plugin<MainThread<'a>: PluginConfigurableAudioPortsImpl>,
+{
+    PluginWrapper::<P>::handle(plugin, |p| {
+        // SAFETY: the host guarantees `requests` is valid for `request_count` elements
+        let requests = unsafe { requests_from_raw(requests, request_count) };
+
+        Ok(p.main_thread()
+            .as_mut()
+            .can_apply_configuration(&requests))
+    })
+    .unwrap_or(false)
+}
+
+#[allow(clippy::missing_safety_doc)]
+unsafe extern "C" fn apply_configuration<P>(
+    plugin: *const clap_plugin,
+    requests: *const clap_audio_port_configuration_request,
+    request_count: u32,
+) -> bool
+where
+    for<'a> P: Plugin<MainThread<'a>: PluginConfigurableAudioPortsImpl>,
+{
+    PluginWrapper::<P>::handle(plugin, |p| {
+        // SAFETY: the host guarantees `requests` is valid for `request_count` elements
+        let requests = unsafe { requests_from_raw(requests, request_count) };
+
+        Ok(p.main_thread().as_mut().apply_configuration(&requests))
+    })
+    .unwrap_or(false)
+}
+
+/// # Safety
+/// `raw` must be valid for reads of `count` elements, unless `count` is `0`.
+unsafe fn requests_from_raw<'a>(
+    raw: *const clap_audio_port_configuration_request,
+    count: u32,
+) -> Vec<AudioPortConfigurationRequest<'a>> {
+    if raw.is_null() || count == 0 {
+        return Vec::new();
+    }
+
+    // SAFETY: the caller guarantees `raw` is valid for `count` elements
+    let raw = unsafe { core::slice::from_raw_parts(raw, count as usize) };
+
+    raw.iter()
+        // SAFETY: the caller guarantees the fields of each request are valid for 'a
+        .map(|r| unsafe { AudioPortConfigurationRequest::from_raw(r) })
+        .collect()
+}
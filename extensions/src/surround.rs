@@ -0,0 +1,90 @@
+use bitflags::bitflags;
+use clack_common::extensions::{Extension, HostExtensionSide, PluginExtensionSide, RawExtension};
+use clap_sys::ext::draft::surround::*;
+use std::ffi::CStr;
+
+#[derive(Copy, Clone)]
+#[allow(dead_code)]
+pub struct PluginSurround(RawExtension<PluginExtensionSide, clap_plugin_surround>);
+
+#[derive(Copy, Clone)]
+#[allow(dead_code)]
+pub struct HostSurround(RawExtension<HostExtensionSide, clap_host_surround>);
+
+// SAFETY: This type is repr(C) and ABI-compatible with the matching extension type.
+unsafe impl Extension for PluginSurround {
+    const IDENTIFIERS: &[&CStr] = &[CLAP_EXT_SURROUND];
+    type ExtensionSide = PluginExtensionSide;
+
+    #[inline]
+    unsafe fn from_raw(raw: RawExtension<Self::ExtensionSide>) -> Self {
+        // SAFETY: the guarantee that this pointer is of the correct type is upheld by the caller.
+        Self(unsafe { raw.cast() })
+    }
+}
+
+// SAFETY: This type is repr(C) and ABI-compatible with the matching extension type.
+unsafe impl Extension for HostSurround {
+    const IDENTIFIERS: &[&CStr] = &[CLAP_EXT_SURROUND];
+    type ExtensionSide = HostExtensionSide;
+
+    #[inline]
+    unsafe fn from_raw(raw: RawExtension<Self::ExtensionSide>) -> Self {
+        // SAFETY: the guarantee that this pointer is of the correct type is upheld by the caller.
+        Self(unsafe { raw.cast() })
+    }
+}
+
+bitflags! {
+    /// A set of standard speaker positions, used to describe the channel layout of a
+    /// [`AudioPortType::SURROUND`](crate::audio_ports::AudioPortType::SURROUND) port.
+    ///
+    /// Each position is a single bit, in CLAP's speaker position order (the `CLAP_SURROUND_*`
+    /// constants), so a channel mask can be built declaratively by combining the flags a port's
+    /// channels actually use.
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct ChannelMask: u64 {
+        /// Front left speaker.
+        const FRONT_LEFT = 1 << 0;
+        /// Front right speaker.
+        const FRONT_RIGHT = 1 << 1;
+        /// Front center speaker.
+        const FRONT_CENTER = 1 << 2;
+        /// Low-frequency effects (subwoofer) channel.
+        const LFE = 1 << 3;
+        /// Rear (surround back) left speaker.
+        const REAR_LEFT = 1 << 4;
+        /// Rear (surround back) right speaker.
+        const REAR_RIGHT = 1 << 5;
+        /// Front left-of-center speaker.
+        const FRONT_LEFT_OF_CENTER = 1 << 6;
+        /// Front right-of-center speaker.
+        const FRONT_RIGHT_OF_CENTER = 1 << 7;
+        /// Rear (surround back) center speaker.
+        const REAR_CENTER = 1 << 8;
+        /// Side left speaker.
+        const SIDE_LEFT = 1 << 9;
+        /// Side right speaker.
+        const SIDE_RIGHT = 1 << 10;
+        /// Top center speaker.
+        const TOP_CENTER = 1 << 11;
+        /// Top front left speaker.
+        const TOP_FRONT_LEFT = 1 << 12;
+        /// Top front center speaker.
+        const TOP_FRONT_CENTER = 1 << 13;
+        /// Top front right speaker.
+        const TOP_FRONT_RIGHT = 1 << 14;
+        /// Top rear left speaker.
+        const TOP_REAR_LEFT = 1 << 15;
+        /// Top rear center speaker.
+        const TOP_REAR_CENTER = 1 << 16;
+        /// Top rear right speaker.
+        const TOP_REAR_RIGHT = 1 << 17;
+    }
+}
+
+#[cfg(feature = "clack-plugin")]
+mod plugin;
+#[cfg(feature = "clack-plugin")]
+pub use plugin::*;
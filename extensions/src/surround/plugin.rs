@@ -0,0 +1,89 @@
+use crate::surround::{ChannelMask, HostSurround, PluginSurround};
+use clack_plugin::extensions::prelude::*;
+use clap_sys::ext::draft::surround::clap_plugin_surround;
+
+/// Reports the actual speaker assignment of a plugin's surround or ambisonic ports.
+///
+/// A port whose type is [`AudioPortType::SURROUND`](crate::audio_ports::AudioPortType::SURROUND)
+/// does not have a well-defined channel ordering on its own; hosts use this extension to
+/// retrieve the channel map for such ports.
+pub trait PluginSurroundImpl {
+    /// Writes the channel map for the port at `port_index` into `out`, and returns the true
+    /// number of channels in that port's map.
+    ///
+    /// If the true channel count is greater than `out.len()`, only `out.len()` channels are
+    /// written; the full count is still returned so the host knows to retry with a bigger
+    /// buffer.
+    fn get_channel_map(&mut self, is_input: bool, port_index: u32, out: &mut [u8]) -> u32;
+
+    /// Returns whether the plugin supports the given channel mask.
+    fn is_channel_mask_supported(&mut self, mask: ChannelMask) -> bool;
+}
+
+// SAFETY: The given struct is the CLAP extension struct for the matching side of this extension.
+unsafe impl<P> ExtensionImplementation<P> for PluginSurround
+where
+    for<'a> P: Plugin<MainThread<'a>: PluginSurroundImpl>,
+{
+    const IMPLEMENTATION: RawExtensionImplementation =
+        RawExtensionImplementation::new(&clap_plugin_surround {
+            is_channel_mask_supported: Some(is_channel_mask_supported::<P>),
+            get_channel_map: Some(get_channel_map::<P>),
+        });
+}
+
+#[allow(clippy::missing_safety_doc)]
+unsafe extern "C" fn is_channel_mask_supported<P>(plugin: *const clap_plugin, mask: u64) -> bool
+where
+    for<'a> P: Plugin<MainThread<'a>: PluginSurroundImpl>,
+{
+    PluginWrapper::<P>::handle(plugin, |p| {
+        Ok(p.main_thread()
+            .as_mut()
+            .is_channel_mask_supported(ChannelMask::from_bits_truncate(mask)))
+    })
+    .unwrap_or(false)
+}
+
+#[allow(clippy::missing_safety_doc)]
+unsafe extern "C" fn get_channel_map<P>(
+    plugin: *const clap_plugin,
+    is_input: bool,
+    port_index: u32,
+    channel_map: *mut u8,
+    channel_map_capacity: u32,
+) -> u32
+where
+    for<'a> P: Plugin<MainThread<'a>: PluginSurroundImpl>,
+{
+    PluginWrapper::<P>::handle(plugin, |p| {
+        if channel_map.is_null() || channel_map_capacity == 0 {
+            return Ok(p
+                .main_thread()
+                .as_mut()
+                .get_channel_map(is_input, port_index, &mut []));
+        }
+
+        // SAFETY: the host guarantees `channel_map` is valid for `channel_map_capacity` elements
+        let out = unsafe {
+            core::slice::from_raw_parts_mut(channel_map, channel_map_capacity as usize)
+        };
+
+        Ok(p.main_thread()
+            .as_mut()
+            .get_channel_map(is_input, port_index, out))
+    })
+    .unwrap_or(0)
+}
+
+impl HostSurround {
+    /// Tells the host that the channel maps or supported channel masks have changed, and that
+    /// it should rescan them.
+    #[inline]
+    pub fn changed(&self, host: &mut HostMainThreadHandle) {
+        if let Some(changed) = host.use_extension(&self.0).changed {
+            // SAFETY: This type ensures the function pointer is valid.
+            unsafe { changed(host.as_raw()) }
+        }
+    }
+}